@@ -1,11 +1,100 @@
 use csv::ReaderBuilder;
 use plotters::prelude::*;
+use plotters_backend::{BackendColor, DrawingErrorKind};
 use smartcore::decomposition::pca::{PCA, PCAParameters};
 use smartcore::linalg::basic::matrix::DenseMatrix;
 use smartcore::linalg::basic::arrays::Array;
 
-fn read_csv(file_path: &str) -> Result<(Vec<Vec<f64>>, Vec<f64>), Box<dyn std::error::Error>> {
+/// Pixel-to-character cell size for `TextDrawingBackend`, chosen so a
+/// 100x38-character grid covers roughly the same 800x600 area the PNG/SVG
+/// backends render at.
+const CONSOLE_CELL_WIDTH: usize = 8;
+const CONSOLE_CELL_HEIGHT: usize = 16;
+
+/// Minimal ASCII-art drawing backend so the scatter chart can be rendered
+/// straight to the terminal, mirroring plotters' console example: pixels are
+/// rasterized into a coarse character grid and printed as text instead of
+/// being written to an image file. Only the scatter chart goes to the
+/// terminal; `main` still writes the scree plot and biplot as PNG files.
+///
+/// Each cell counts how many pixels landed in it rather than just on/off,
+/// and prints that count on a five-step ramp (` .:+#`), so sparsely hit
+/// cells read lighter than densely filled ones. White pixels (the
+/// background fill) and faint pixels with alpha at or below 0.3 are not
+/// counted. A one-pixel line fully crossing a cell adds 8 hits if
+/// horizontal and 16 if vertical.
+struct TextDrawingBackend {
+    width: usize,
+    height: usize,
+    hits: Vec<u32>,
+}
+
+impl TextDrawingBackend {
+    fn new(width: usize, height: usize) -> Self {
+        TextDrawingBackend {
+            width,
+            height,
+            hits: vec![0; width * height],
+        }
+    }
+}
+
+impl DrawingBackend for TextDrawingBackend {
+    type ErrorType = std::convert::Infallible;
+
+    fn get_size(&self) -> (u32, u32) {
+        (
+            (self.width * CONSOLE_CELL_WIDTH) as u32,
+            (self.height * CONSOLE_CELL_HEIGHT) as u32,
+        )
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        const LEVELS: [char; 5] = [' ', '.', ':', '+', '#'];
+        for row in 0..self.height {
+            let line: String = (0..self.width)
+                .map(|col| {
+                    let level = match self.hits[row * self.width + col] {
+                        0 => 0,
+                        1..=2 => 1,
+                        3..=5 => 2,
+                        6..=10 => 3,
+                        _ => 4,
+                    };
+                    LEVELS[level]
+                })
+                .collect();
+            println!("{line}");
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: (i32, i32),
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha > 0.3 && color.rgb != (255, 255, 255) && point.0 >= 0 && point.1 >= 0 {
+            let col = (point.0 as usize / CONSOLE_CELL_WIDTH).min(self.width - 1);
+            let row = (point.1 as usize / CONSOLE_CELL_HEIGHT).min(self.height - 1);
+            self.hits[row * self.width + col] += 1;
+        }
+        Ok(())
+    }
+}
+
+fn read_csv(
+    file_path: &str,
+) -> Result<(Vec<Vec<f64>>, Vec<f64>, Vec<String>), Box<dyn std::error::Error>> {
     let mut rdr = ReaderBuilder::new().has_headers(true).from_path(file_path)?;
+    let feature_names: Vec<String> = {
+        let headers = rdr.headers()?;
+        headers.iter().take(headers.len() - 1).map(String::from).collect()
+    };
     let mut data: Vec<Vec<f64>> = Vec::new();
     let mut targets: Vec<f64> = Vec::new();
 
@@ -19,10 +108,60 @@ fn read_csv(file_path: &str) -> Result<(Vec<Vec<f64>>, Vec<f64>), Box<dyn std::e
         targets.push(target);
     }
 
-    Ok((data, targets))
+    Ok((data, targets, feature_names))
+}
+
+// Column scaling applied before fitting PCA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Preprocessing {
+    /// Fit on the raw values (covariance matrix, unscaled).
+    None,
+    /// Subtract each column's mean (covariance matrix, centered).
+    Center,
+    /// Subtract the mean and divide by the sample standard deviation
+    /// (equivalent to fitting on the correlation matrix).
+    Standardize,
+}
+
+/// Returns a mean-centered copy of `data`, for `Standardize` also scaled
+/// column by column to unit variance; `None` returns an unchanged copy.
+/// Zero-variance columns are left centered-only rather than divided by zero.
+fn preprocess_columns(data: &[Vec<f64>], mode: Preprocessing) -> Vec<Vec<f64>> {
+    if mode == Preprocessing::None {
+        return data.to_vec();
+    }
+
+    let n_samples = data.len();
+    let n_features = data[0].len();
+    let mut result = data.to_vec();
+
+    for j in 0..n_features {
+        let mean: f64 = data.iter().map(|row| row[j]).sum::<f64>() / n_samples as f64;
+        for row in result.iter_mut() {
+            row[j] -= mean;
+        }
+
+        if mode == Preprocessing::Standardize {
+            let variance: f64 = result.iter().map(|row| row[j].powi(2)).sum::<f64>()
+                / (n_samples - 1) as f64;
+            let std_dev = variance.sqrt();
+            if std_dev > f64::EPSILON {
+                for row in result.iter_mut() {
+                    row[j] /= std_dev;
+                }
+            }
+            // Zero-variance column: leave it centered only.
+        }
+    }
+
+    result
 }
 
-fn perform_pca(data: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, Box<dyn std::error::Error>> {
+fn perform_pca(
+    data: &[Vec<f64>],
+    n_components: usize,
+    preprocessing: Preprocessing,
+) -> Result<(Vec<Vec<f64>>, Vec<Vec<f64>>), Box<dyn std::error::Error>> {
     // Check for empty data or inconsistent row lengths
     if data.is_empty() {
         return Err("No data provided".into());
@@ -31,33 +170,217 @@ fn perform_pca(data: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, Box<dyn std::error::E
     if !data.iter().all(|row| row.len() == n_features) {
         return Err("Inconsistent number of features in data".into());
     }
+    if n_components < 2 || n_components > n_features {
+        return Err("n_components must be between 2 and the number of features".into());
+    }
+
+    // Apply the requested preprocessing before building the matrix PCA fits on.
+    let data = preprocess_columns(data, preprocessing);
 
     // Convert to DenseMatrix (column-major)
     let n_samples = data.len();
     let flat_data: Vec<f64> = data.iter().flatten().cloned().collect();
-    let x = DenseMatrix::new(n_samples, n_features, flat_data,false);
+    let x = DenseMatrix::new(n_samples, n_features, flat_data, false);
+
+    // `preprocess_columns` already applies whatever scaling `preprocessing`
+    // asked for (including the z-score divide for `Standardize`), so PCA
+    // always fits on the covariance matrix of the data as given to it here —
+    // asking smartcore to also use its own correlation-matrix normalization
+    // would standardize an already-standardized matrix.
+    let pca = PCA::fit(
+        &x,
+        PCAParameters::default()
+            .with_n_components(n_components)
+            .with_use_correlation_matrix(false),
+    )?;
 
-    // Perform PCA
-    let pca = PCA::fit(&x, PCAParameters::default().with_n_components(2))?;
-    
     // Transform data
     let result = pca.transform(&x)?;
-    
+
     // Convert back to Vec<Vec<f64>>
     let mut projected_data = Vec::with_capacity(n_samples);
     for i in 0..n_samples {
-        let mut row = Vec::with_capacity(2);
-        for j in 0..2 {
+        let mut row = Vec::with_capacity(n_components);
+        for j in 0..n_components {
             row.push(*result.get((i, j)));
         }
         projected_data.push(row);
     }
 
-    Ok(projected_data)
+    // Loadings: how much each original feature contributes to each component.
+    // smartcore's `components()` is already (n_features x n_components), so
+    // this just reshapes it into a Vec<Vec<f64>> callers can index by feature.
+    let components = pca.components();
+    let mut loadings = vec![vec![0.0; n_components]; n_features];
+    for f in 0..n_features {
+        for c in 0..n_components {
+            loadings[f][c] = *components.get((f, c));
+        }
+    }
+
+    Ok((projected_data, loadings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perform_pca_loadings_are_feature_major() {
+        // 4 features, 2 components: regression test for a transposed-index
+        // bug where `components.get((c, f))` panicked as soon as
+        // n_features > n_components (the normal case).
+        let data = vec![
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![2.0, 1.0, 4.0, 3.0],
+            vec![3.0, 4.0, 1.0, 2.0],
+            vec![4.0, 3.0, 2.0, 1.0],
+        ];
+        let (projected, loadings) = perform_pca(&data, 2, Preprocessing::None).unwrap();
+
+        assert_eq!(projected.len(), 4);
+        assert_eq!(loadings.len(), 4);
+        for row in &loadings {
+            assert_eq!(row.len(), 2);
+        }
+    }
+}
+
+/// Sample variance of each principal component, in the same order `perform_pca`
+/// produced them. This is the explained variance (the eigenvalue of the
+/// covariance/correlation matrix along that axis), computed directly off the
+/// projected coordinates rather than reaching into PCA internals.
+fn explained_variance(projected_data: &[Vec<f64>]) -> Vec<f64> {
+    let n_samples = projected_data.len();
+    let n_components = projected_data[0].len();
+    let mut variances = Vec::with_capacity(n_components);
+
+    for j in 0..n_components {
+        let mean: f64 = projected_data.iter().map(|row| row[j]).sum::<f64>() / n_samples as f64;
+        let variance: f64 = projected_data
+            .iter()
+            .map(|row| (row[j] - mean).powi(2))
+            .sum::<f64>()
+            / (n_samples - 1) as f64;
+        variances.push(variance);
+    }
+
+    variances
+}
+
+/// Turns per-component variances into explained-variance ratio and its
+/// running total, so callers can decide how many components to keep.
+fn explained_variance_ratio(variances: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let total: f64 = variances.iter().sum();
+    let ratio: Vec<f64> = variances.iter().map(|v| v / total).collect();
+    let mut cumulative = Vec::with_capacity(ratio.len());
+    let mut running = 0.0;
+    for r in &ratio {
+        running += r;
+        cumulative.push(running);
+    }
+    (ratio, cumulative)
 }
 
-fn plot_pca_results(projected_data: &[Vec<f64>], targets: &[f64]) -> Result<(), Box<dyn std::error::Error>> {
-    let root = BitMapBackend::new("pca_results.png", (800, 600)).into_drawing_area();
+/// Distinct target values in first-seen order, used to assign each class a
+/// stable color index. Shared by every render path so a 3+-class dataset
+/// gets consistent, distinguishable colors regardless of which chart draws
+/// it, instead of falling back to a binary RED/BLUE split.
+fn distinct_classes(targets: &[f64]) -> Vec<f64> {
+    let mut classes: Vec<f64> = Vec::new();
+    for &t in targets {
+        if !classes.contains(&t) {
+            classes.push(t);
+        }
+    }
+    classes
+}
+
+fn class_color(classes: &[f64], value: f64) -> RGBAColor {
+    let index = classes.iter().position(|&c| c == value).unwrap_or(0);
+    Palette99::pick(index).mix(1.0)
+}
+
+fn plot_scree(variance_ratio: &[f64], cumulative_ratio: &[f64]) -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new("scree_plot.png", (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let n_components = variance_ratio.len();
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("Scree Plot", ("sans-serif", 40).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .right_y_label_area_size(40)
+        .build_cartesian_2d(0..n_components, 0f64..1.0)?
+        .set_secondary_coord(0..n_components, 0f64..1.0);
+
+    chart
+        .configure_mesh()
+        .x_desc("Principal Component")
+        .y_desc("Explained Variance Ratio")
+        .draw()?;
+    chart
+        .configure_secondary_axes()
+        .y_desc("Cumulative Explained Variance")
+        .draw()?;
+
+    // Per-component variance as bars on the primary axis.
+    chart.draw_series(variance_ratio.iter().enumerate().map(|(i, &ratio)| {
+        Rectangle::new([(i, 0.0), (i + 1, ratio)], BLUE.mix(0.6).filled())
+    }))?;
+
+    // Cumulative curve on the secondary axis.
+    chart.draw_secondary_series(LineSeries::new(
+        cumulative_ratio
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (i, c)),
+        &RED,
+    ))?;
+
+    Ok(())
+}
+
+/// Output target for the 2D scatter plot. The chart-building logic in
+/// `render_pca_chart` is backend-generic; only the backend construction here
+/// differs per mode.
+enum OutputFormat {
+    Png,
+    Svg,
+    Console,
+}
+
+fn plot_pca_results(
+    projected_data: &[Vec<f64>],
+    targets: &[f64],
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match output {
+        OutputFormat::Png => {
+            let root = BitMapBackend::new("pca_results.png", (800, 600)).into_drawing_area();
+            render_pca_chart(root, projected_data, targets)
+        }
+        OutputFormat::Svg => {
+            let root = SVGBackend::new("pca_results.svg", (800, 600)).into_drawing_area();
+            render_pca_chart(root, projected_data, targets)
+        }
+        OutputFormat::Console => {
+            let root = TextDrawingBackend::new(100, 38).into_drawing_area();
+            render_pca_chart(root, projected_data, targets)
+        }
+    }
+}
+
+fn render_pca_chart<DB: DrawingBackend>(
+    root: DrawingArea<DB, plotters::coord::Shift>,
+    projected_data: &[Vec<f64>],
+    targets: &[f64],
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
     root.fill(&WHITE)?;
 
     // Calculate plot bounds
@@ -75,15 +398,172 @@ fn plot_pca_results(projected_data: &[Vec<f64>], targets: &[f64]) -> Result<(),
 
     chart.configure_mesh().draw()?;
 
+    let classes = distinct_classes(targets);
+
+    for &class in &classes {
+        let color = class_color(&classes, class);
+        let points: Vec<(f64, f64)> = projected_data
+            .iter()
+            .zip(targets)
+            .filter(|(_, &t)| t == class)
+            .map(|(point, _)| (point[0], point[1]))
+            .collect();
+
+        chart
+            .draw_series(
+                points
+                    .iter()
+                    .map(|&p| Circle::new(p, 5, ShapeStyle::from(&color).filled())),
+            )?
+            .label(format!("class {class}"))
+            .legend(move |(x, y)| Circle::new((x, y), 5, ShapeStyle::from(&color).filled()));
+    }
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()?;
+
+    // PNG/SVG backends flush on drop, but `TextDrawingBackend` has no `Drop`
+    // impl, so without this call `--output console` silently prints nothing.
+    root.present()?;
+
+    Ok(())
+}
+
+fn plot_pca_results_3d(projected_data: &[Vec<f64>], targets: &[f64]) -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new("pca_results_3d.png", (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    // Calculate plot bounds per axis
+    let x_min = projected_data.iter().map(|v| v[0]).fold(f64::INFINITY, |a, b| a.min(b)) - 1.0;
+    let x_max = projected_data.iter().map(|v| v[0]).fold(f64::NEG_INFINITY, |a, b| a.max(b)) + 1.0;
+    let y_min = projected_data.iter().map(|v| v[1]).fold(f64::INFINITY, |a, b| a.min(b)) - 1.0;
+    let y_max = projected_data.iter().map(|v| v[1]).fold(f64::NEG_INFINITY, |a, b| a.max(b)) + 1.0;
+    let z_min = projected_data.iter().map(|v| v[2]).fold(f64::INFINITY, |a, b| a.min(b)) - 1.0;
+    let z_max = projected_data.iter().map(|v| v[2]).fold(f64::NEG_INFINITY, |a, b| a.max(b)) + 1.0;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("PCA Results (3 components)", ("sans-serif", 40).into_font())
+        .margin(5)
+        .build_cartesian_3d(x_min..x_max, y_min..y_max, z_min..z_max)?;
+
+    chart.with_projection(|mut pb| {
+        pb.yaw = 0.5;
+        pb.pitch = 0.3;
+        pb.scale = 0.9;
+        pb.into_matrix()
+    });
+
+    chart.configure_axes().draw()?;
+
+    let classes = distinct_classes(targets);
+
+    // Depth-sort so points nearer the viewer are drawn last and overlay farther ones.
+    let mut order: Vec<usize> = (0..projected_data.len()).collect();
+    order.sort_by(|&a, &b| {
+        let depth_a = projected_data[a][0] + projected_data[a][1] + projected_data[a][2];
+        let depth_b = projected_data[b][0] + projected_data[b][1] + projected_data[b][2];
+        depth_a.partial_cmp(&depth_b).unwrap()
+    });
+
+    for i in order {
+        let point = &projected_data[i];
+        let color = class_color(&classes, targets[i]);
+        chart.draw_series(std::iter::once(Circle::new(
+            (point[0], point[1], point[2]),
+            5,
+            ShapeStyle::from(&color).filled(),
+        )))?;
+    }
+
+    Ok(())
+}
+
+/// Biplot: the PC1/PC2 sample scatter overlaid with an arrow per original
+/// feature, pointing from the origin to that feature's loading on the first
+/// two components. Arrow length shows how strongly the feature drives each
+/// axis, which is the usual way to read what a component "means".
+fn plot_biplot(
+    projected_data: &[Vec<f64>],
+    targets: &[f64],
+    loadings: &[Vec<f64>],
+    feature_names: &[String],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = BitMapBackend::new("biplot.png", (800, 600)).into_drawing_area();
+    root.fill(&WHITE)?;
+
+    let x_min = projected_data.iter().map(|v| v[0]).fold(f64::INFINITY, |a, b| a.min(b)) - 1.0;
+    let x_max = projected_data.iter().map(|v| v[0]).fold(f64::NEG_INFINITY, |a, b| a.max(b)) + 1.0;
+    let y_min = projected_data.iter().map(|v| v[1]).fold(f64::INFINITY, |a, b| a.min(b)) - 1.0;
+    let y_max = projected_data.iter().map(|v| v[1]).fold(f64::NEG_INFINITY, |a, b| a.max(b)) + 1.0;
+
+    let mut chart = ChartBuilder::on(&root)
+        .caption("PCA Biplot", ("sans-serif", 40).into_font())
+        .margin(5)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(x_min..x_max, y_min..y_max)?;
+
+    chart.configure_mesh().draw()?;
+
+    let classes = distinct_classes(targets);
+
     for (i, point) in projected_data.iter().enumerate() {
-        let color = if targets[i] == 1.0 { RED } else { BLUE };
-        chart.draw_series(std::iter::once(
-            Circle::new(
-                (point[0], point[1]),
-                5,
-                ShapeStyle::from(&color).filled(),
-            )
-        ))?;
+        let color = class_color(&classes, targets[i]);
+        chart.draw_series(std::iter::once(Circle::new(
+            (point[0], point[1]),
+            4,
+            ShapeStyle::from(&color).filled(),
+        )))?;
+    }
+
+    // Scale loading vectors so the longest one reaches roughly 40% of the
+    // point cloud's extent, instead of plotting raw (and usually tiny)
+    // loadings on the sample coordinate scale.
+    let cloud_extent = (x_max - x_min).max(y_max - y_min);
+    let max_loading_len = loadings
+        .iter()
+        .map(|l| l[0].hypot(l[1]))
+        .fold(0.0_f64, f64::max);
+    let scale = if max_loading_len > f64::EPSILON {
+        (cloud_extent * 0.4) / max_loading_len
+    } else {
+        1.0
+    };
+    let head_len = cloud_extent * 0.03;
+
+    for (feature, loading) in feature_names.iter().zip(loadings) {
+        let tip = (loading[0] * scale, loading[1] * scale);
+        let angle = loading[1].atan2(loading[0]);
+
+        chart.draw_series(std::iter::once(PathElement::new(
+            vec![(0.0, 0.0), tip],
+            &BLACK,
+        )))?;
+
+        // Arrowhead: a small triangle at the tip, pointing along the shaft.
+        let back = (tip.0 - head_len * angle.cos(), tip.1 - head_len * angle.sin());
+        let spread = std::f64::consts::FRAC_PI_2;
+        let left = (
+            back.0 + head_len * 0.4 * (angle + spread).cos(),
+            back.1 + head_len * 0.4 * (angle + spread).sin(),
+        );
+        let right = (
+            back.0 + head_len * 0.4 * (angle - spread).cos(),
+            back.1 + head_len * 0.4 * (angle - spread).sin(),
+        );
+        chart.draw_series(std::iter::once(Polygon::new(
+            vec![tip, left, right],
+            BLACK.filled(),
+        )))?;
+
+        chart.draw_series(std::iter::once(Text::new(
+            feature.clone(),
+            tip,
+            ("sans-serif", 15).into_font(),
+        )))?;
     }
 
     Ok(())
@@ -91,9 +571,66 @@ fn plot_pca_results(projected_data: &[Vec<f64>], targets: &[f64]) -> Result<(),
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let file_path = "data.csv";
-    let (data, targets) = read_csv(file_path)?;
-    let projected_data = perform_pca(&data)?;
-    plot_pca_results(&projected_data, &targets)?;
-    println!("PCA completed successfully. Results saved to pca_results.png");
+
+    // `--components <n>` picks how many principal components to compute; 3 switches
+    // the renderer over to the 3D scatter path, anything else stays 2D.
+    let args: Vec<String> = std::env::args().collect();
+    let n_components = args
+        .iter()
+        .position(|a| a == "--components")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(2);
+
+    // `--preprocessing none|center|standardize` selects the column scaling
+    // applied before fitting; standardizing is usually the right call when
+    // feature columns are on very different scales.
+    let preprocessing = args
+        .iter()
+        .position(|a| a == "--preprocessing")
+        .and_then(|i| args.get(i + 1))
+        .map(|v| match v.as_str() {
+            "center" => Preprocessing::Center,
+            "standardize" => Preprocessing::Standardize,
+            _ => Preprocessing::None,
+        })
+        .unwrap_or(Preprocessing::None);
+
+    // `--output console|png|svg` picks the 2D scatter's rendering backend.
+    let output = args
+        .iter()
+        .position(|a| a == "--output")
+        .and_then(|i| args.get(i + 1))
+        .map(|v| match v.as_str() {
+            "console" => OutputFormat::Console,
+            "svg" => OutputFormat::Svg,
+            _ => OutputFormat::Png,
+        })
+        .unwrap_or(OutputFormat::Png);
+
+    let (data, targets, feature_names) = read_csv(file_path)?;
+    let (projected_data, loadings) = perform_pca(&data, n_components, preprocessing)?;
+
+    // The scree plot fits every component so each ratio is a share of the
+    // total variance, not only of the components kept above.
+    let (all_components, _) = perform_pca(&data, data[0].len(), preprocessing)?;
+    let variances = explained_variance(&all_components);
+    let (variance_ratio, cumulative_ratio) = explained_variance_ratio(&variances);
+    plot_scree(&variance_ratio, &cumulative_ratio)?;
+    println!("Scree plot saved to scree_plot.png");
+
+    if n_components == 3 {
+        plot_pca_results_3d(&projected_data, &targets)?;
+        println!("PCA completed successfully. Results saved to pca_results_3d.png");
+    } else {
+        plot_pca_results(&projected_data, &targets, output)?;
+        println!("PCA completed successfully.");
+    }
+
+    // `perform_pca` already rejects n_components < 2, so PC1 and PC2 are
+    // guaranteed to be present in every loading row by the time we get here.
+    plot_biplot(&projected_data, &targets, &loadings, &feature_names)?;
+    println!("Biplot saved to biplot.png");
+
     Ok(())
 }
\ No newline at end of file